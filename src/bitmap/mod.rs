@@ -1,20 +1,47 @@
 use std::collections::HashMap;
+use std::path::Path;
 
-use image::{RgbImage, EncodableLayout};
+use image::{EncodableLayout, RgbImage, RgbaImage};
 
 use super::{
     BitmapBuffer, Error, FontDesc, FontKey, GlyphKey, Metrics, RasterizedGlyph, Size,
 };
 
 // The first visable character is the '!', which is at index 33 in unicode, but index 1
-// in the glyph sheet. (Index 0 is reserved for metrics information).
+// in the glyph sheet. (Index 0 is reserved for metrics information). This is also the
+// cell layout used when no sidecar glyph map is present next to the atlas.
 const FIRST_CHARACTER: usize = 33;
 
-// Pixels are loaded as RGB.
-const PIXEL_COMPONENTS: usize = 3;
+// Extension of the sidecar manifest that lists the codepoints/ranges covered by each
+// cell of the atlas, in cell order (row-major, wrapping after `columns` cells).
+const GLYPH_MAP_EXTENSION: &str = "glyphs";
+
+// The decoded bitmap atlas, kept either as RGB or RGBA depending on whether the source
+// image carried an alpha channel.
+enum BitmapImage {
+    Rgb(RgbImage),
+    Rgba(RgbaImage),
+}
+
+impl BitmapImage {
+    fn as_bytes(&self) -> &[u8] {
+        match self {
+            BitmapImage::Rgb(img) => img.as_bytes(),
+            BitmapImage::Rgba(img) => img.as_bytes(),
+        }
+    }
+
+    fn pixel_components(&self) -> usize {
+        match self {
+            BitmapImage::Rgb(_) => 3,
+            BitmapImage::Rgba(_) => 4,
+        }
+    }
+}
 
 struct BitmapFont {
-    img: RgbImage,
+    img: BitmapImage,
+    has_alpha: bool,
     atlas_width: usize,
     padding_width: usize,
     average_advance: usize,
@@ -23,52 +50,216 @@ struct BitmapFont {
     underline_thickness: usize,
     strikeout_position: usize,
     strikeout_thickness: usize,
+    columns: usize,
+    rows: usize,
+    glyph_map: HashMap<char, usize>,
+    // Per-cell (advance, left-bearing) overrides for proportional atlases; cells absent
+    // here use `average_advance` with no left-bearing, same as a monospace sheet.
+    advances: HashMap<usize, (f32, f32)>,
+    kerning: HashMap<(char, char), (f32, f32)>,
+}
+
+impl BitmapFont {
+    // The atlas is baked at a fixed pixel size, so glyphs/metrics are resampled by the
+    // ratio between the requested size and that baked-in line height.
+    fn scale_for(&self, size: Size, device_pixel_ratio: f32) -> f32 {
+        let requested_px = size.as_f32_pts() * device_pixel_ratio;
+        requested_px / self.line_height as f32
+    }
 }
 
 pub struct BitmapRasterizer {
     fonts: HashMap<FontKey, BitmapFont>,
     keys: HashMap<FontDesc, FontKey>,
+    device_pixel_ratio: f32,
+    fallbacks: HashMap<FontKey, Vec<FontKey>>,
+    replacement_glyphs: HashMap<FontKey, GlyphKey>,
 }
 
 impl BitmapRasterizer {
+    /// Registers `fallback` as the next font to try when `primary` can't produce a glyph.
+    /// Fallbacks are tried in the order they were added.
+    pub fn add_fallback(&mut self, primary: FontKey, fallback: FontKey) {
+        self.fallbacks.entry(primary).or_insert_with(Vec::new).push(fallback);
+    }
+
+    /// Configures the glyph rendered for `primary` once the primary font and its entire
+    /// fallback chain fail to produce one. Without this, a built-in hollow notdef box is
+    /// used instead.
+    pub fn set_replacement_glyph(&mut self, primary: FontKey, replacement: GlyphKey) {
+        self.replacement_glyphs.insert(primary, replacement);
+    }
+
+    // Tries the primary font, then walks its registered fallback chain, then a
+    // caller-configured replacement glyph, and finally synthesizes a notdef box so a
+    // string never fails to render.
+    fn rasterize_with_fallback(&self, glyph: GlyphKey) -> Result<RasterizedGlyph, Error> {
+        if let Ok(rasterized) = self.rasterize_glyph(glyph) {
+            return Ok(rasterized);
+        }
+
+        if let Some(fallbacks) = self.fallbacks.get(&glyph.font_key) {
+            for &fallback_key in fallbacks {
+                let fallback_glyph = GlyphKey { font_key: fallback_key, ..glyph };
+
+                if let Ok(rasterized) = self.rasterize_glyph(fallback_glyph) {
+                    return Ok(rasterized);
+                }
+            }
+        }
+
+        if let Some(&replacement) = self.replacement_glyphs.get(&glyph.font_key) {
+            if let Ok(rasterized) = self.rasterize_glyph(replacement) {
+                return Ok(rasterized);
+            }
+        }
+
+        self.notdef_glyph(glyph)
+    }
+
+    // A simple hollow box, scaled to the glyph cell the caller asked for and advancing by
+    // the font's scaled average advance, used when no font in the fallback chain has the
+    // requested character and no replacement glyph was configured.
+    fn notdef_glyph(&self, glyph: GlyphKey) -> Result<RasterizedGlyph, Error> {
+        let loaded_font = self.get_loaded_font(glyph.font_key)?;
+
+        let pixel_components = loaded_font.img.pixel_components();
+        let scale = loaded_font.scale_for(glyph.size, self.device_pixel_ratio);
+        let width = scale_dimension(loaded_font.average_advance, scale);
+        let height = scale_dimension(loaded_font.line_height, scale);
+
+        let mut data = vec![0u8; width * height * pixel_components];
+        for y in 0..height {
+            for x in 0..width {
+                if x != 0 && y != 0 && x != width - 1 && y != height - 1 {
+                    continue;
+                }
+
+                let i = (x + y * width) * pixel_components;
+                data[i] = 255;
+                data[i + 1] = 255;
+                data[i + 2] = 255;
+
+                if loaded_font.has_alpha {
+                    data[i + 3] = 255;
+                }
+            }
+        }
+
+        let buffer = if loaded_font.has_alpha {
+            BitmapBuffer::Rgba(data)
+        } else {
+            BitmapBuffer::Rgb(data)
+        };
+
+        let advance = loaded_font.average_advance as f32 * scale;
+
+        Ok(RasterizedGlyph {
+            character: glyph.character,
+            width: width as i32,
+            height: height as i32,
+            top: height as i32,
+            left: 0,
+            advance: (advance.round() as i32, 0),
+            buffer,
+        })
+    }
+
     fn rasterize_glyph(
         &self,
         glyph: GlyphKey,
     ) -> Result<RasterizedGlyph, Error> {
         let character = glyph.character;
-        let character_index = character as usize;
-
-        if character_index < FIRST_CHARACTER {
-            return Err(Error::UnknownFontKey)
-        }
 
         let loaded_font = self.get_loaded_font(glyph.font_key)?;
 
-        let buffer = {
-            let mut data = Vec::<u8>::new();
+        let cell = *loaded_font
+            .glyph_map
+            .get(&character)
+            .ok_or(Error::PlatformError(format!("No glyph mapped for character {:?}", character)))?;
+
+        let pixel_components = loaded_font.img.pixel_components();
+
+        let data = {
             let font_data = loaded_font.img.as_bytes();
 
-            let x_offset = (loaded_font.average_advance + loaded_font.padding_width) * (character_index - FIRST_CHARACTER + 1);
+            let cell_width = loaded_font.average_advance + loaded_font.padding_width;
+            let x_offset = (cell % loaded_font.columns) * cell_width;
+            let y_offset = (cell / loaded_font.columns) * loaded_font.line_height;
+
+            if x_offset + loaded_font.average_advance > loaded_font.atlas_width
+                || cell / loaded_font.columns >= loaded_font.rows
+            {
+                return Err(Error::PlatformError(format!(
+                    "Glyph cell for {:?} falls outside the atlas bounds",
+                    character
+                )));
+            }
+
+            let last_row = y_offset + loaded_font.line_height - 1;
+            let last_column = x_offset + loaded_font.average_advance - 1;
+            let last_index = (last_column + last_row * loaded_font.atlas_width) * pixel_components
+                + pixel_components
+                - 1;
+
+            if last_index >= font_data.len() {
+                return Err(Error::PlatformError(format!(
+                    "Glyph cell for {:?} reads past the end of the decoded atlas",
+                    character
+                )));
+            }
+
+            let mut data = Vec::<u8>::new();
 
             for y in 0..loaded_font.line_height {
                 for x in 0..loaded_font.average_advance {
-                    let i = (x + x_offset + y * loaded_font.atlas_width) * PIXEL_COMPONENTS;
+                    let i = (x + x_offset + (y + y_offset) * loaded_font.atlas_width) * pixel_components;
                     data.push(font_data[i]);
                     data.push(font_data[i + 1]);
                     data.push(font_data[i + 2]);
+
+                    if loaded_font.has_alpha {
+                        data.push(font_data[i + 3]);
+                    }
                 }
             }
 
+            data
+        };
+
+        let scale = loaded_font.scale_for(glyph.size, self.device_pixel_ratio);
+
+        let scaled_width = scale_dimension(loaded_font.average_advance, scale);
+        let scaled_height = scale_dimension(loaded_font.line_height, scale);
+
+        let data = resample_bilinear(
+            &data,
+            loaded_font.average_advance,
+            loaded_font.line_height,
+            pixel_components,
+            scaled_width,
+            scaled_height,
+        );
+
+        let buffer = if loaded_font.has_alpha {
+            BitmapBuffer::Rgba(data)
+        } else {
             BitmapBuffer::Rgb(data)
         };
 
+        let (advance, left) = loaded_font
+            .advances
+            .get(&cell)
+            .copied()
+            .unwrap_or((loaded_font.average_advance as f32, 0.0));
+
         Ok(RasterizedGlyph {
             character,
-            width: loaded_font.average_advance as i32,
-            height: loaded_font.line_height as i32,
-            top: loaded_font.line_height as i32,
-            left: 0,
-            advance: (0, 0),
+            width: scaled_width as i32,
+            height: scaled_height as i32,
+            top: scaled_height as i32,
+            left: (left * scale).round() as i32,
+            advance: ((advance * scale).round() as i32, 0),
             buffer,
         })
     }
@@ -79,24 +270,29 @@ impl BitmapRasterizer {
 }
 
 impl crate::Rasterize for BitmapRasterizer {
-    fn new(_device_pixel_ratio: f32) -> Result<BitmapRasterizer, Error> {
+    fn new(device_pixel_ratio: f32) -> Result<BitmapRasterizer, Error> {
         Ok(BitmapRasterizer {
             fonts: HashMap::new(),
             keys: HashMap::new(),
+            device_pixel_ratio,
+            fallbacks: HashMap::new(),
+            replacement_glyphs: HashMap::new(),
         })
     }
 
-    fn metrics(&self, key: FontKey, _size: Size) -> Result<Metrics, Error> {
+    fn metrics(&self, key: FontKey, size: Size) -> Result<Metrics, Error> {
         let loaded_font = self.get_loaded_font(key)?;
 
+        let scale = loaded_font.scale_for(size, self.device_pixel_ratio) as f64;
+
         Ok(Metrics {
             descent: 0.0,
-            average_advance: loaded_font.average_advance as f64,
-            line_height: loaded_font.line_height as f64,
-            underline_position: loaded_font.underline_position as f32,
-            underline_thickness: loaded_font.underline_thickness as f32,
-            strikeout_position: loaded_font.strikeout_position as f32,
-            strikeout_thickness: loaded_font.strikeout_thickness as f32,
+            average_advance: loaded_font.average_advance as f64 * scale,
+            line_height: loaded_font.line_height as f64 * scale,
+            underline_position: loaded_font.underline_position as f32 * scale as f32,
+            underline_thickness: loaded_font.underline_thickness as f32 * scale as f32,
+            strikeout_position: loaded_font.strikeout_position as f32 * scale as f32,
+            strikeout_thickness: loaded_font.strikeout_thickness as f32 * scale as f32,
         })
     }
 
@@ -106,19 +302,39 @@ impl crate::Rasterize for BitmapRasterizer {
             Err(_) => return Err(Error::FontNotFound(desc.clone())),
         };
 
-        let font_img = match font_file.decode() {
-            Ok(img) => img.into_rgb8(),
+        let font_img_dyn = match font_file.decode() {
+            Ok(img) => img,
             Err(_) => return Err(Error::PlatformError("Failed to decode font".into())),
         };
 
-        let font_atlas_width = font_img.width() as usize;
-        let font_atlas_height = font_img.height() as usize;
+        let has_alpha = font_img_dyn.color().has_alpha();
+        let font_img = if has_alpha {
+            BitmapImage::Rgba(font_img_dyn.into_rgba8())
+        } else {
+            BitmapImage::Rgb(font_img_dyn.into_rgb8())
+        };
+
+        let font_atlas_width = match &font_img {
+            BitmapImage::Rgb(img) => img.width() as usize,
+            BitmapImage::Rgba(img) => img.width() as usize,
+        };
+        let font_atlas_height = match &font_img {
+            BitmapImage::Rgb(img) => img.height() as usize,
+            BitmapImage::Rgba(img) => img.height() as usize,
+        };
 
+        let pixel_components = font_img.pixel_components();
         let font_data = font_img.as_bytes();
 
+        // The sidecar manifest (if any) declares how many atlas rows there are, since the
+        // metrics marker column only describes a single cell row's extent.
+        let manifest = load_glyph_manifest(&desc.name);
+        let rows = manifest.as_ref().map_or(1, |manifest| manifest.rows).max(1);
+        let line_height = font_atlas_height / rows;
+
         let mut average_advance = 0;
         for x in 0..(font_atlas_width as usize) {
-            if check_pixel_color(font_data, font_atlas_width, x, 0, 0, 255, 0) {
+            if check_pixel_color(font_data, font_atlas_width, pixel_components, x, 0, 0, 255, 0)? {
                 average_advance = x + 1;
                 break;
             }
@@ -130,19 +346,27 @@ impl crate::Rasterize for BitmapRasterizer {
 
         let mut padding_width = 0;
         for x in average_advance..font_atlas_width {
-            if !check_pixel_color(font_data, font_atlas_width, x, 0, 255, 0, 255) {
+            if !check_pixel_color(font_data, font_atlas_width, pixel_components, x, 0, 255, 0, 255)? {
                 break;
             }
 
             padding_width += 1;
         }
 
+        let cell_width = average_advance + padding_width;
+        if font_atlas_width % cell_width != 0 {
+            return Err(Error::PlatformError(format!(
+                "Atlas width {} is not an exact multiple of the cell width {}",
+                font_atlas_width, cell_width
+            )));
+        }
+
         let mut underline_position = 0;
         let mut underline_thickness = 0;
-        for y in 0..(font_atlas_height as usize) {
-            if check_pixel_color(font_data, font_atlas_width, 0, y, 255, 0, 0) {
+        for y in 0..line_height {
+            if check_pixel_color(font_data, font_atlas_width, pixel_components, 0, y, 255, 0, 0)? {
                 if underline_position == 0 {
-                    underline_position = font_atlas_height - y;
+                    underline_position = line_height - y;
                 }
 
                 underline_thickness += 1;
@@ -155,10 +379,10 @@ impl crate::Rasterize for BitmapRasterizer {
 
         let mut strikeout_position = 0;
         let mut strikeout_thickness = 0;
-        for y in 0..(font_atlas_height as usize) {
-            if check_pixel_color(font_data, font_atlas_width, 0, y, 0, 0, 255) {
+        for y in 0..line_height {
+            if check_pixel_color(font_data, font_atlas_width, pixel_components, 0, y, 0, 0, 255)? {
                 if strikeout_position == 0 {
-                    strikeout_position = font_atlas_height - y;
+                    strikeout_position = line_height - y;
                 }
 
                 strikeout_thickness += 1;
@@ -169,18 +393,30 @@ impl crate::Rasterize for BitmapRasterizer {
             return Err(Error::PlatformError("Can't determine font strikeout position".into()));
         }
 
+        let columns = font_atlas_width / cell_width;
+        let (glyph_map, advances, kerning) = match manifest {
+            Some(manifest) => (manifest.glyph_map, manifest.advances, manifest.kerning),
+            None => (default_ascii_glyph_map(columns), HashMap::new(), HashMap::new()),
+        };
+
         let key = FontKey::next();
         self.keys.insert(desc.clone(), key);
         self.fonts.insert(key, BitmapFont {
             img: font_img,
+            has_alpha,
             atlas_width: font_atlas_width,
-            line_height: font_atlas_height,
+            line_height,
             padding_width,
             average_advance,
             underline_position,
             underline_thickness,
             strikeout_position,
             strikeout_thickness,
+            columns,
+            rows,
+            glyph_map,
+            advances,
+            kerning,
         });
 
         Ok(key)
@@ -188,21 +424,436 @@ impl crate::Rasterize for BitmapRasterizer {
 
     fn get_glyph(&mut self, glyph: GlyphKey) -> Result<RasterizedGlyph, Error> {
         let rasterized_glyph =
-            self.rasterize_glyph(glyph)?;
+            self.rasterize_with_fallback(glyph)?;
 
         Ok(rasterized_glyph)
     }
 
-    fn kerning(&mut self, _left: GlyphKey, _right: GlyphKey) -> (f32, f32) {
-        (0., 0.)
+    fn kerning(&mut self, left: GlyphKey, right: GlyphKey) -> (f32, f32) {
+        let loaded_font = match self.get_loaded_font(left.font_key) {
+            Ok(loaded_font) => loaded_font,
+            Err(_) => return (0., 0.),
+        };
+
+        let scale = loaded_font.scale_for(left.size, self.device_pixel_ratio);
+
+        loaded_font
+            .kerning
+            .get(&(left.character, right.character))
+            .map(|&(x, y)| (x * scale, y * scale))
+            .unwrap_or((0., 0.))
     }
 
-    fn update_dpr(&mut self, _device_pixel_ratio: f32) {
+    fn update_dpr(&mut self, device_pixel_ratio: f32) {
+        self.device_pixel_ratio = device_pixel_ratio;
     }
 }
 
-fn check_pixel_color(data: &[u8], atlas_width: usize, x: usize, y: usize, r: u8, g: u8, b: u8) -> bool {
-    let i  = (x + y * atlas_width) * PIXEL_COMPONENTS as usize;
+// Sidecar glyph manifest, parsed from a `.glyphs` file next to the atlas image. Lines are
+// one of:
+//   rows=N                  declares the atlas row count (defaults to 1 if absent)
+//   kerning:XY:ADVANCE,LEFT adjustment applied between character X followed by Y
+//   X                       a single character, assigned the next cell in order
+//   X-Z                     an inclusive codepoint range, assigned consecutive cells
+// A glyph entry may carry an `@ADVANCE,LEFT` suffix to record a proportional advance and
+// left-bearing for every cell it assigns; entries without it keep the monospace default.
+struct GlyphManifest {
+    rows: usize,
+    glyph_map: HashMap<char, usize>,
+    advances: HashMap<usize, (f32, f32)>,
+    kerning: HashMap<(char, char), (f32, f32)>,
+}
+
+fn parse_advance(spec: &str) -> Option<(f32, f32)> {
+    let (advance, left) = spec.split_once(',')?;
+    Some((advance.trim().parse().ok()?, left.trim().parse().ok()?))
+}
+
+fn load_glyph_manifest(image_path: &str) -> Option<GlyphManifest> {
+    let manifest_path = Path::new(image_path).with_extension(GLYPH_MAP_EXTENSION);
+    let contents = std::fs::read_to_string(&manifest_path).ok()?;
+
+    let mut rows = 1;
+    let mut glyph_map = HashMap::new();
+    let mut advances = HashMap::new();
+    let mut kerning = HashMap::new();
+    // Cell 0 (row 0, column 0) is the atlas's reserved metrics rectangle that
+    // `check_pixel_color` scans for marker pixels, so the first real glyph cell is 1 —
+    // same convention as `default_ascii_glyph_map`.
+    let mut cell = 1;
 
-    data[i] == r && data[i + 1] == g && data[i + 2] == b
+    for line in contents.lines() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(value) = line.strip_prefix("rows=") {
+            rows = value.trim().parse().unwrap_or(1);
+            continue;
+        }
+
+        if let Some(rest) = line.strip_prefix("kerning:") {
+            let (pair, amounts) = rest.split_once(':')?;
+            let mut pair = pair.chars();
+            let left_char = pair.next()?;
+            let right_char = pair.next()?;
+            let amounts = parse_advance(amounts)?;
+            kerning.insert((left_char, right_char), amounts);
+            continue;
+        }
+
+        let (spec, advance) = match line.split_once('@') {
+            Some((spec, advance)) => (spec, parse_advance(advance)),
+            None => (line, None),
+        };
+
+        if let Some((start, end)) = spec.split_once('-') {
+            let start = start.trim().chars().next()?;
+            let end = end.trim().chars().next()?;
+
+            for codepoint in (start as u32)..=(end as u32) {
+                if let Some(character) = char::from_u32(codepoint) {
+                    glyph_map.insert(character, cell);
+
+                    if let Some(advance) = advance {
+                        advances.insert(cell, advance);
+                    }
+
+                    cell += 1;
+                }
+            }
+        } else {
+            let character = spec.trim().chars().next()?;
+            glyph_map.insert(character, cell);
+
+            if let Some(advance) = advance {
+                advances.insert(cell, advance);
+            }
+
+            cell += 1;
+        }
+    }
+
+    Some(GlyphManifest { rows, glyph_map, advances, kerning })
+}
+
+// Falls back to the original contiguous-ASCII-starting-at-'!' layout when no sidecar
+// manifest is present, so existing single-row atlases keep working unmodified.
+fn default_ascii_glyph_map(columns: usize) -> HashMap<char, usize> {
+    let mut glyph_map = HashMap::new();
+
+    for cell in 1..columns {
+        if let Some(character) = char::from_u32((FIRST_CHARACTER + cell - 1) as u32) {
+            glyph_map.insert(character, cell);
+        }
+    }
+
+    glyph_map
+}
+
+fn scale_dimension(dimension: usize, scale: f32) -> usize {
+    (dimension as f32 * scale).round().max(1.0) as usize
+}
+
+// Bilinear resampling over raw interleaved pixel bytes, mirroring fontdue's approach of
+// scaling all glyph geometry by a single factor so metrics and rasterized glyphs agree.
+fn resample_bilinear(
+    src: &[u8],
+    src_width: usize,
+    src_height: usize,
+    pixel_components: usize,
+    dst_width: usize,
+    dst_height: usize,
+) -> Vec<u8> {
+    let mut dst = vec![0u8; dst_width * dst_height * pixel_components];
+
+    if src_width == 0 || src_height == 0 || dst_width == 0 || dst_height == 0 {
+        return dst;
+    }
+
+    let x_ratio = src_width as f32 / dst_width as f32;
+    let y_ratio = src_height as f32 / dst_height as f32;
+
+    for dy in 0..dst_height {
+        let sy = ((dy as f32 + 0.5) * y_ratio - 0.5).max(0.0);
+        let y0 = sy.floor() as usize;
+        let y1 = (y0 + 1).min(src_height - 1);
+        let y_frac = sy - y0 as f32;
+
+        for dx in 0..dst_width {
+            let sx = ((dx as f32 + 0.5) * x_ratio - 0.5).max(0.0);
+            let x0 = sx.floor() as usize;
+            let x1 = (x0 + 1).min(src_width - 1);
+            let x_frac = sx - x0 as f32;
+
+            for c in 0..pixel_components {
+                let p00 = src[(y0 * src_width + x0) * pixel_components + c] as f32;
+                let p10 = src[(y0 * src_width + x1) * pixel_components + c] as f32;
+                let p01 = src[(y1 * src_width + x0) * pixel_components + c] as f32;
+                let p11 = src[(y1 * src_width + x1) * pixel_components + c] as f32;
+
+                let top = p00 + (p10 - p00) * x_frac;
+                let bottom = p01 + (p11 - p01) * x_frac;
+                let value = top + (bottom - top) * y_frac;
+
+                dst[(dy * dst_width + dx) * pixel_components + c] = value.round().clamp(0.0, 255.0) as u8;
+            }
+        }
+    }
+
+    dst
+}
+
+// Markers are read from row 0 and column 0 of the atlas. For an RGBA sheet a marker cell
+// must additionally be fully opaque, since colored glyph cells may otherwise collide with
+// the RGB values used to mark metrics. A truncated or mis-sized image yields a recoverable
+// error here instead of panicking on the index below.
+fn check_pixel_color(
+    data: &[u8],
+    atlas_width: usize,
+    pixel_components: usize,
+    x: usize,
+    y: usize,
+    r: u8,
+    g: u8,
+    b: u8,
+) -> Result<bool, Error> {
+    let i = (x + y * atlas_width) * pixel_components;
+
+    if i + pixel_components > data.len() {
+        return Err(Error::PlatformError(format!(
+            "Marker pixel at ({}, {}) is out of bounds of the decoded atlas",
+            x, y
+        )));
+    }
+
+    let is_match = data[i] == r && data[i + 1] == g && data[i + 2] == b;
+
+    Ok(if pixel_components == 4 {
+        is_match && data[i + 3] == 255
+    } else {
+        is_match
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resample_bilinear_identity_is_exact() {
+        let src: Vec<u8> = vec![
+            10, 20, 30, 40, 50, 60,
+            70, 80, 90, 100, 110, 120,
+        ];
+
+        let dst = resample_bilinear(&src, 2, 2, 3, 2, 2);
+
+        assert_eq!(dst, src);
+    }
+
+    #[test]
+    fn resample_bilinear_upscales_uniform_color() {
+        let src = vec![10, 20, 30];
+
+        let dst = resample_bilinear(&src, 1, 1, 3, 4, 4);
+
+        assert_eq!(dst.len(), 4 * 4 * 3);
+        for chunk in dst.chunks(3) {
+            assert_eq!(chunk, &[10, 20, 30]);
+        }
+    }
+
+    #[test]
+    fn resample_bilinear_zero_size_returns_zeroed_buffer() {
+        let dst = resample_bilinear(&[], 0, 0, 3, 4, 4);
+
+        assert_eq!(dst, vec![0u8; 4 * 4 * 3]);
+    }
+
+    #[test]
+    fn resample_bilinear_downscale_stays_in_byte_range() {
+        let src: Vec<u8> = (0..(8 * 8 * 3)).map(|i| (i % 256) as u8).collect();
+
+        let dst = resample_bilinear(&src, 8, 8, 3, 3, 3);
+
+        assert_eq!(dst.len(), 3 * 3 * 3);
+    }
+
+    // `load_glyph_manifest` reads a `.glyphs` file next to the given image path, so these
+    // write a throwaway sidecar under the test's own unique temp path and clean it up.
+    fn with_manifest<T>(unique: &str, contents: &str, test: impl FnOnce(&str) -> T) -> T {
+        let image_path = std::env::temp_dir().join(format!("crossfont_bitmap_test_{}.png", unique));
+        let manifest_path = image_path.with_extension(GLYPH_MAP_EXTENSION);
+
+        std::fs::write(&manifest_path, contents).unwrap();
+        let result = test(image_path.to_str().unwrap());
+        std::fs::remove_file(&manifest_path).unwrap();
+
+        result
+    }
+
+    #[test]
+    fn load_glyph_manifest_missing_file_returns_none() {
+        let image_path = std::env::temp_dir().join("crossfont_bitmap_test_missing.png");
+
+        assert!(load_glyph_manifest(image_path.to_str().unwrap()).is_none());
+    }
+
+    #[test]
+    fn load_glyph_manifest_parses_rows_directive() {
+        with_manifest("rows", "rows=3\nA\nB\n", |path| {
+            let manifest = load_glyph_manifest(path).unwrap();
+
+            assert_eq!(manifest.rows, 3);
+            assert_eq!(manifest.glyph_map.get(&'A'), Some(&1));
+            assert_eq!(manifest.glyph_map.get(&'B'), Some(&2));
+        });
+    }
+
+    #[test]
+    fn load_glyph_manifest_defaults_to_one_row_without_directive() {
+        with_manifest("norows", "A\n", |path| {
+            let manifest = load_glyph_manifest(path).unwrap();
+
+            assert_eq!(manifest.rows, 1);
+        });
+    }
+
+    #[test]
+    fn load_glyph_manifest_expands_ranges_in_order() {
+        with_manifest("range", "A-C\n", |path| {
+            let manifest = load_glyph_manifest(path).unwrap();
+
+            assert_eq!(manifest.glyph_map.get(&'A'), Some(&1));
+            assert_eq!(manifest.glyph_map.get(&'B'), Some(&2));
+            assert_eq!(manifest.glyph_map.get(&'C'), Some(&3));
+        });
+    }
+
+    #[test]
+    fn load_glyph_manifest_parses_advance_suffix() {
+        with_manifest("advance", "W@12.5,1.0\n", |path| {
+            let manifest = load_glyph_manifest(path).unwrap();
+
+            assert_eq!(manifest.glyph_map.get(&'W'), Some(&1));
+            assert_eq!(manifest.advances.get(&1), Some(&(12.5, 1.0)));
+        });
+    }
+
+    #[test]
+    fn load_glyph_manifest_parses_kerning_pairs() {
+        with_manifest("kerning", "kerning:AV:-1.5,0.25\n", |path| {
+            let manifest = load_glyph_manifest(path).unwrap();
+
+            assert_eq!(manifest.kerning.get(&('A', 'V')), Some(&(-1.5, 0.25)));
+        });
+    }
+
+    #[test]
+    fn load_glyph_manifest_skips_blank_lines_and_comments() {
+        with_manifest("comments", "# a comment\n\nA\n", |path| {
+            let manifest = load_glyph_manifest(path).unwrap();
+
+            assert_eq!(manifest.glyph_map.len(), 1);
+            assert_eq!(manifest.glyph_map.get(&'A'), Some(&1));
+        });
+    }
+
+    #[test]
+    fn load_glyph_manifest_reserves_cell_zero_for_metrics() {
+        with_manifest("reserved", "A-C\n", |path| {
+            let manifest = load_glyph_manifest(path).unwrap();
+
+            assert!(
+                manifest.glyph_map.values().all(|&cell| cell != 0),
+                "no manifest entry may be assigned the reserved metrics cell 0"
+            );
+        });
+    }
+
+    fn assert_all_sampled_pixels_are(buffer: &BitmapBuffer, value: u8) {
+        let data = match buffer {
+            BitmapBuffer::Rgb(data) => data,
+            BitmapBuffer::Rgba(data) => data,
+        };
+
+        assert!(
+            data.iter().all(|&byte| byte == value),
+            "expected every sampled byte to be {}, got {:?}",
+            value,
+            data
+        );
+    }
+
+    // A glyph mapped to cell 1 must be read from cell 1, never the reserved metrics cell 0
+    // (`load_glyph_manifest_reserves_cell_zero_for_metrics` only checks the parsed
+    // manifest; this builds the glyph map by hand and drives `rasterize_glyph` against a
+    // real atlas, so reading from cell 0 would surface marker-pixel garbage instead of the
+    // glyph).
+    #[test]
+    fn rasterize_glyph_skips_reserved_metrics_cell() {
+        let (width, height) = (9u32, 4u32);
+        let mut img = RgbImage::new(width, height);
+
+        for y in 0..height {
+            for x in 0..width {
+                img.put_pixel(x, y, image::Rgb([0, 0, 0]));
+            }
+        }
+
+        // Cell 0 (x in 0..3) carries the metrics markers: average_advance = 2, padding = 1,
+        // underline at y = 1, strikeout at y = 2. Everything else in cell 0 stays black.
+        img.put_pixel(1, 0, image::Rgb([0, 255, 0]));
+        img.put_pixel(2, 0, image::Rgb([255, 0, 255]));
+        img.put_pixel(0, 1, image::Rgb([255, 0, 0]));
+        img.put_pixel(0, 2, image::Rgb([0, 0, 255]));
+
+        // Cell 1 (x in 3..6) is the real glyph data for 'A', filled solid white so it's
+        // unmistakably distinct from cell 0's markers/black filler.
+        for y in 0..height {
+            for x in 3..6 {
+                img.put_pixel(x, y, image::Rgb([255, 255, 255]));
+            }
+        }
+
+        let mut glyph_map = HashMap::new();
+        glyph_map.insert('A', 1);
+        glyph_map.insert('B', 2);
+
+        let font = BitmapFont {
+            img: BitmapImage::Rgb(img),
+            has_alpha: false,
+            atlas_width: width as usize,
+            padding_width: 1,
+            average_advance: 2,
+            line_height: height as usize,
+            underline_position: 3,
+            underline_thickness: 1,
+            strikeout_position: 2,
+            strikeout_thickness: 1,
+            columns: 3,
+            rows: 1,
+            glyph_map,
+            advances: HashMap::new(),
+            kerning: HashMap::new(),
+        };
+
+        let font_key = FontKey::next();
+        let mut rasterizer = BitmapRasterizer {
+            fonts: HashMap::new(),
+            keys: HashMap::new(),
+            device_pixel_ratio: 1.0,
+            fallbacks: HashMap::new(),
+            replacement_glyphs: HashMap::new(),
+        };
+        rasterizer.fonts.insert(font_key, font);
+
+        let glyph = GlyphKey { font_key, character: 'A', size: Size::new(4.0) };
+        let rasterized = rasterizer.rasterize_glyph(glyph).expect("cell 1 is within atlas bounds");
+
+        assert_all_sampled_pixels_are(&rasterized.buffer, 255);
+    }
 }